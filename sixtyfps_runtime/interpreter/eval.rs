@@ -78,6 +78,46 @@ impl Default for Value {
 
 impl corelib::rtti::ValueType for Value {}
 
+impl std::fmt::Display for Value {
+    /// A human-readable rendering of a `Value`, suitable for showing the
+    /// result the expression REPL (see `repl.rs`) returns for an evaluated
+    /// line; the REPL itself returns the `Value` and does not print it.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Void => write!(f, "()"),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::String(s) => write!(f, "{:?}", s.as_str()),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Resource(Resource::AbsoluteFilePath(path)) => {
+                write!(f, "@image-url(\"{}\")", path)
+            }
+            Value::Resource(_) => write!(f, "@image-url(<embedded>)"),
+            Value::Array(items) => {
+                write!(f, "[")?;
+                for (i, v) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", v)?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(map) => {
+                write!(f, "{{")?;
+                for (i, (k, v)) in map.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", k, v)?;
+                }
+                write!(f, "}}")
+            }
+            Value::Color(c) => write!(f, "{:?}", c),
+            Value::PathElements(_) => write!(f, "<path>"),
+        }
+    }
+}
+
 /// Helper macro to implement the TryFrom / TryInto for Value
 ///
 /// For example
@@ -114,19 +154,261 @@ declare_value_conversion!(Object => [HashMap<String, Value>] );
 declare_value_conversion!(Color => [Color] );
 declare_value_conversion!(PathElements => [PathElements]);
 
-/// Evaluate an expression and return a Value as the result of this expression
+/// A location within a `.60` source file that an [`EvalError`] can point at.
+///
+/// `Expression` does not yet carry spans from the compiler, so this is
+/// attached opportunistically by whichever call site still has the relevant
+/// source text on hand, rather than being threaded through every node of the
+/// tree. Today the only such call site is the expression REPL (see
+/// `repl.rs`), and the span it attaches covers the whole line it parsed, not
+/// the failing sub-expression within it — `ComponentDescription` doesn't
+/// retain the source text of individual bindings, so errors raised while
+/// evaluating a compiled `.60` binding (as opposed to a REPL-typed
+/// expression) still carry `span: None`. Errors for which no span could be
+/// recovered simply carry `None` and render without a source snippet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    /// The file name, as it was given to the compiler, if any.
+    pub file: Option<Rc<str>>,
+    /// The full text of the source file, used to extract the offending line.
+    pub source: Rc<str>,
+    /// Byte offset of the start of the span within `source`.
+    pub offset: usize,
+    /// Length in bytes of the span.
+    pub len: usize,
+}
+
+impl Span {
+    /// Returns the 1-based (line, column) of `self.offset` within `self.source`,
+    /// together with the full text of that line.
+    fn line_column_and_text(&self) -> (usize, usize, &str) {
+        let mut line = 1;
+        let mut line_start = 0;
+        for (idx, ch) in self.source.char_indices() {
+            if idx >= self.offset {
+                break;
+            }
+            if ch == '\n' {
+                line += 1;
+                line_start = idx + 1;
+            }
+        }
+        let line_text = self.source[line_start..].lines().next().unwrap_or("");
+        let column = self.offset - line_start + 1;
+        (line, column, line_text)
+    }
+}
+
+/// An error produced while evaluating an [`Expression`].
+///
+/// Carries a [`Span`] when the call site had one to attach (today: only the
+/// whole evaluated line, and only from the REPL — see [`Span`]'s doc), a
+/// human readable message, and the expected/actual [`Type`] for type
+/// mismatches. This replaces the `panic!`/`todo!` calls that used to abort
+/// the whole process on a malformed `.60` binding: tooling can catch this
+/// error, render it with [`EvalError::render`], and keep running.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvalError {
+    pub span: Option<Span>,
+    pub message: String,
+    pub expected: Option<Type>,
+    pub got: Option<Type>,
+}
+
+impl EvalError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        Self { span: None, message: message.into(), expected: None, got: None }
+    }
+
+    pub(crate) fn with_span(span: Option<Span>, message: impl Into<String>) -> Self {
+        Self { span, message: message.into(), expected: None, got: None }
+    }
+
+    pub(crate) fn type_mismatch(span: Option<Span>, expected: Type, got: Type) -> Self {
+        Self {
+            message: format!("expected a value of type {:?}, got {:?}", expected, got),
+            span,
+            expected: Some(expected),
+            got: Some(got),
+        }
+    }
+
+    /// Overrides the message of an already-built error, keeping its
+    /// `span`/`expected`/`got`. Used to attach operator-specific context to a
+    /// [`Self::type_mismatch`] without losing the type info it already set.
+    pub(crate) fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Attaches `span` to this error if it doesn't already carry one more
+    /// specific. This is how spans get attached opportunistically: a call
+    /// site that holds onto the original source text (currently only the
+    /// expression REPL) can wrap whatever `EvalError` comes back with the
+    /// span of the text it evaluated, since `Expression` itself carries no
+    /// span to recover one from automatically. Bindings evaluated from a
+    /// compiled `.60` file don't go through a call site like this yet, so
+    /// they still render without a source snippet.
+    pub(crate) fn with_fallback_span(mut self, span: Span) -> Self {
+        if self.span.is_none() {
+            self.span = Some(span);
+        }
+        self
+    }
+
+    /// Renders this error as an ariadne-style report: the message, followed by
+    /// the file/line/column and the offending source line with a `^` underline
+    /// beneath the span, when one is known.
+    pub fn render(&self) -> String {
+        let mut out = format!("error: {}\n", self.message);
+        if let Some(span) = &self.span {
+            let (line, column, line_text) = span.line_column_and_text();
+            let file = span.file.as_deref().unwrap_or("<unknown>");
+            out += &format!("  --> {}:{}:{}\n", file, line, column);
+            let gutter = format!("{}", line);
+            out += &format!("{:width$} |\n", "", width = gutter.len());
+            out += &format!("{} | {}\n", gutter, line_text);
+            let underline_len = span.len.max(1).min(line_text.len().saturating_sub(column - 1).max(1));
+            out += &format!(
+                "{:width$} | {}{}\n",
+                "",
+                " ".repeat(column - 1),
+                "^".repeat(underline_len),
+                width = gutter.len()
+            );
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// The `Type` a runtime `Value` was produced from, used to fill in
+/// `EvalError::expected`/`got` at the "unsupported operator" error sites
+/// instead of leaving them `None`.
+fn value_type(v: &Value) -> Type {
+    match v {
+        Value::Void => Type::Void,
+        Value::Number(_) => Type::Float32,
+        Value::String(_) => Type::String,
+        Value::Bool(_) => Type::Bool,
+        Value::Resource(_) => Type::Resource,
+        Value::Array(_) => Type::Array(Box::new(Type::Void)),
+        Value::Object(_) => Type::Object(HashMap::new()),
+        Value::Color(_) => Type::Color,
+        Value::PathElements(_) => Type::PathElements,
+    }
+}
+
+/// Coerces a `Number`/`String` operand pair to a pair of `String`s, mirroring
+/// the number-to-string coercion `Expression::Cast { to: Type::String, .. }`
+/// already performs, so that e.g. `"value: " + some_number` works the same
+/// way a binding with an explicit cast would.
+fn coerce_numeric_string_operands(lhs: Value, rhs: Value) -> (Value, Value) {
+    let to_string = |v: Value| match v {
+        Value::Number(n) => Value::String(SharedString::from(format!("{}", n).as_str())),
+        other => other,
+    };
+    match (&lhs, &rhs) {
+        (Value::String(_), Value::Number(_)) | (Value::Number(_), Value::String(_)) => {
+            (to_string(lhs), to_string(rhs))
+        }
+        _ => (lhs, rhs),
+    }
+}
+
+/// Evaluates a `BinaryExpression`'s operator on already-evaluated operands.
+/// Shared between `eval_expression` and the closures `compile_expression`
+/// produces, so the two keep supporting the exact same set of operators.
+fn eval_binary_op(op: char, lhs: Value, rhs: Value) -> Result<Value, EvalError> {
+    // Equality compares the operands as-is: unlike `+`/ordering, it was never
+    // asked to treat a `Number` and a `String` that merely look alike as
+    // equal, so it must run before the Number/String coercion below, not
+    // after it.
+    match op {
+        '=' => return Ok(Value::Bool(lhs == rhs)),
+        '!' => return Ok(Value::Bool(lhs != rhs)),
+        _ => {}
+    }
+    let (lhs, rhs) = coerce_numeric_string_operands(lhs, rhs);
+    match (op, lhs, rhs) {
+        ('+', Value::Number(a), Value::Number(b)) => Ok(Value::Number(a + b)),
+        ('-', Value::Number(a), Value::Number(b)) => Ok(Value::Number(a - b)),
+        ('/', Value::Number(a), Value::Number(b)) => Ok(Value::Number(a / b)),
+        ('*', Value::Number(a), Value::Number(b)) => Ok(Value::Number(a * b)),
+        ('%', Value::Number(a), Value::Number(b)) => Ok(Value::Number(a % b)),
+        ('<', Value::Number(a), Value::Number(b)) => Ok(Value::Bool(a < b)),
+        ('>', Value::Number(a), Value::Number(b)) => Ok(Value::Bool(a > b)),
+        ('≤', Value::Number(a), Value::Number(b)) => Ok(Value::Bool(a <= b)),
+        ('≥', Value::Number(a), Value::Number(b)) => Ok(Value::Bool(a >= b)),
+        ('+', Value::String(a), Value::String(b)) => {
+            Ok(Value::String(SharedString::from(format!("{}{}", a, b).as_str())))
+        }
+        ('<', Value::String(a), Value::String(b)) => Ok(Value::Bool(a.as_str() < b.as_str())),
+        ('>', Value::String(a), Value::String(b)) => Ok(Value::Bool(a.as_str() > b.as_str())),
+        ('≤', Value::String(a), Value::String(b)) => Ok(Value::Bool(a.as_str() <= b.as_str())),
+        ('≥', Value::String(a), Value::String(b)) => Ok(Value::Bool(a.as_str() >= b.as_str())),
+        ('&', Value::Bool(a), Value::Bool(b)) => Ok(Value::Bool(a && b)),
+        ('|', Value::Bool(a), Value::Bool(b)) => Ok(Value::Bool(a || b)),
+        (op, lhs, rhs) => {
+            let (expected, got) = (value_type(&lhs), value_type(&rhs));
+            Err(EvalError::type_mismatch(None, expected, got)
+                .with_message(format!("unsupported {:?} {} {:?}", lhs, op, rhs)))
+        }
+    }
+}
+
+/// Evaluates a `SelfAssignment`'s operator (`+=`/`-=`/`*=`/`/=`/`%=`) on
+/// already-evaluated operands.
+fn eval_self_assignment_op(op: char, lhs: Value, rhs: Value) -> Result<Value, EvalError> {
+    let (lhs, rhs) = coerce_numeric_string_operands(lhs, rhs);
+    match (lhs, rhs, op) {
+        (Value::Number(a), Value::Number(b), '+') => Ok(Value::Number(a + b)),
+        (Value::Number(a), Value::Number(b), '-') => Ok(Value::Number(a - b)),
+        (Value::Number(a), Value::Number(b), '/') => Ok(Value::Number(a / b)),
+        (Value::Number(a), Value::Number(b), '*') => Ok(Value::Number(a * b)),
+        (Value::Number(a), Value::Number(b), '%') => Ok(Value::Number(a % b)),
+        (Value::String(a), Value::String(b), '+') => {
+            Ok(Value::String(SharedString::from(format!("{}{}", a, b).as_str())))
+        }
+        (lhs, rhs, op) => {
+            let (expected, got) = (value_type(&lhs), value_type(&rhs));
+            Err(EvalError::type_mismatch(None, expected, got)
+                .with_message(format!("unsupported {:?} {}= {:?}", lhs, op, rhs)))
+        }
+    }
+}
+
+/// Evaluate an expression and return a Value as the result of this expression,
+/// or an [`EvalError`] describing what went wrong (invalid/unsupported
+/// expressions, type mismatches, ...) instead of panicking.
+///
+/// `Expression::CodeBlock` below evaluates every statement in order and keeps
+/// the value of the last one. That's genuinely all it can do today:
+/// early-exit control flow (`return`/`break`/`continue`) would need
+/// `Expression` variants the compiler doesn't emit, and those live in
+/// `sixtyfps_compilerlib` (outside this crate) — there is no unwinding
+/// mechanism to wire up on this side without them. Unlocking real imperative callback bodies needs
+/// the compiler-side `Expression` variants first; this function is where
+/// their evaluation would be added once they exist.
 pub fn eval_expression(
     e: &Expression,
     component_type: &crate::ComponentDescription,
     eval_context: &corelib::EvaluationContext,
-) -> Value {
+) -> Result<Value, EvalError> {
     match e {
-        Expression::Invalid => panic!("invalid expression while evaluating"),
-        Expression::Uncompiled(_) => panic!("uncompiled expression while evaluating"),
-        Expression::StringLiteral(s) => Value::String(s.as_str().into()),
-        Expression::NumberLiteral(n) => Value::Number(*n),
-        Expression::BoolLiteral(b) => Value::Bool(*b),
-        Expression::SignalReference { .. } => panic!("signal in expression"),
+        Expression::Invalid => Err(EvalError::new("invalid expression while evaluating")),
+        Expression::Uncompiled(_) => Err(EvalError::new("uncompiled expression while evaluating")),
+        Expression::StringLiteral(s) => Ok(Value::String(s.as_str().into())),
+        Expression::NumberLiteral(n) => Ok(Value::Number(*n)),
+        Expression::BoolLiteral(b) => Ok(Value::Bool(*b)),
+        Expression::SignalReference { .. } => Err(EvalError::new("signal in expression")),
         Expression::PropertyReference(NamedReference { element, name }) => {
             let element = element.upgrade().unwrap();
             let (component_mem, component_type, eval_context) =
@@ -135,33 +417,33 @@ pub fn eval_expression(
             if element.id == element.enclosing_component.upgrade().unwrap().root_element.borrow().id
             {
                 if let Some(x) = component_type.custom_properties.get(name) {
-                    return unsafe {
+                    return Ok(unsafe {
                         x.prop
                             .get(Pin::new_unchecked(&*component_mem.add(x.offset)), &eval_context)
                             .unwrap()
-                    };
+                    });
                 }
             };
             let item_info = &component_type.items[element.id.as_str()];
             core::mem::drop(element);
             let item = unsafe { item_info.item_from_component(component_mem) };
-            item_info.rtti.properties[name.as_str()].get(item, &eval_context)
+            Ok(item_info.rtti.properties[name.as_str()].get(item, &eval_context))
         }
         Expression::RepeaterIndexReference { element } => {
             if element.upgrade().unwrap().borrow().base_type
                 == Type::Component(component_type.original.clone())
             {
                 let x = &component_type.custom_properties["index"];
-                unsafe {
+                Ok(unsafe {
                     x.prop
                         .get(
                             Pin::new_unchecked(&*eval_context.component.as_ptr().add(x.offset)),
                             &eval_context,
                         )
                         .unwrap()
-                }
+                })
             } else {
-                todo!();
+                Err(EvalError::new("repeater index reference outside of a repeated element"))
             }
         }
         Expression::RepeaterModelReference { element } => {
@@ -169,42 +451,42 @@ pub fn eval_expression(
                 == Type::Component(component_type.original.clone())
             {
                 let x = &component_type.custom_properties["model_data"];
-                unsafe {
+                Ok(unsafe {
                     x.prop
                         .get(
                             Pin::new_unchecked(&*eval_context.component.as_ptr().add(x.offset)),
                             &eval_context,
                         )
                         .unwrap()
-                }
+                })
             } else {
-                todo!();
+                Err(EvalError::new("repeater model reference outside of a repeated element"))
             }
         }
         Expression::ObjectAccess { base, name } => {
-            if let Value::Object(mut o) = eval_expression(base, component_type, eval_context) {
-                o.remove(name).unwrap_or(Value::Void)
+            if let Value::Object(mut o) = eval_expression(base, component_type, eval_context)? {
+                Ok(o.remove(name).unwrap_or(Value::Void))
             } else {
-                Value::Void
+                Ok(Value::Void)
             }
         }
         Expression::Cast { from, to } => {
-            let v = eval_expression(&*from, component_type, eval_context);
-            match (v, to) {
+            let v = eval_expression(&*from, component_type, eval_context)?;
+            Ok(match (v, to) {
                 (Value::Number(n), Type::Int32) => Value::Number(n.round()),
                 (Value::Number(n), Type::String) => {
                     Value::String(SharedString::from(format!("{}", n).as_str()))
                 }
                 (Value::Number(n), Type::Color) => Value::Color(Color::from(n as u32)),
                 (v, _) => v,
-            }
+            })
         }
         Expression::CodeBlock(sub) => {
             let mut v = Value::Void;
             for e in sub {
-                v = eval_expression(e, component_type, eval_context);
+                v = eval_expression(e, component_type, eval_context)?;
             }
-            v
+            Ok(v)
         }
         Expression::FunctionCall { function, .. } => {
             if let Expression::SignalReference(NamedReference { element, name }) = &**function {
@@ -226,26 +508,20 @@ pub fn eval_expression(
                                 .get(name.as_str())
                                 .map(|o| component_mem.add(*o))
                         })
-                        .unwrap_or_else(|| panic!("unkown signal {}", name))
+                        .ok_or_else(|| EvalError::new(format!("unknown signal {}", name)))?
                         as *mut corelib::Signal<()>)
                 };
                 signal.emit(eval_context, ());
-                Value::Void
+                Ok(Value::Void)
             } else {
-                panic!("call of something not a signal")
+                Err(EvalError::new("call of something that is not a signal"))
             }
         }
         Expression::SelfAssignment { lhs, rhs, op } => match &**lhs {
             Expression::PropertyReference(NamedReference { element, name }) => {
-                let eval = |lhs| {
-                    let rhs = eval_expression(&**rhs, component_type, eval_context);
-                    match (lhs, rhs, op) {
-                        (Value::Number(a), Value::Number(b), '+') => Value::Number(a + b),
-                        (Value::Number(a), Value::Number(b), '-') => Value::Number(a - b),
-                        (Value::Number(a), Value::Number(b), '/') => Value::Number(a / b),
-                        (Value::Number(a), Value::Number(b), '*') => Value::Number(a * b),
-                        (lhs, rhs, op) => panic!("unsupported {:?} {} {:?}", lhs, op, rhs),
-                    }
+                let eval = |lhs: Value| -> Result<Value, EvalError> {
+                    let rhs = eval_expression(&**rhs, component_type, eval_context)?;
+                    eval_self_assignment_op(*op, lhs, rhs)
                 };
 
                 let element = element.upgrade().unwrap();
@@ -257,80 +533,76 @@ pub fn eval_expression(
                     if let Some(x) = component_type.custom_properties.get(name) {
                         unsafe {
                             let p = Pin::new_unchecked(&*component_mem.add(x.offset));
-                            x.prop
-                                .set(p, eval(x.prop.get(p, &eval_context).unwrap()), None)
-                                .unwrap();
+                            let new_value = eval(x.prop.get(p, &eval_context).unwrap())?;
+                            x.prop.set(p, new_value, None).unwrap();
                         }
-                        return Value::Void;
+                        return Ok(Value::Void);
                     }
                 };
                 let item_info = &component_type.items[element.borrow().id.as_str()];
                 let item = unsafe { item_info.item_from_component(component_mem) };
                 let p = &item_info.rtti.properties[name.as_str()];
-                p.set(item, eval(p.get(item, &eval_context)), None);
-                Value::Void
+                let new_value = eval(p.get(item, &eval_context))?;
+                p.set(item, new_value, None);
+                Ok(Value::Void)
             }
-            _ => panic!("typechecking should make sure this was a PropertyReference"),
+            _ => Err(EvalError::new("typechecking should make sure the lhs of a self-assignment is a property reference")),
         },
         Expression::BinaryExpression { lhs, rhs, op } => {
-            let lhs = eval_expression(&**lhs, component_type, eval_context);
-            let rhs = eval_expression(&**rhs, component_type, eval_context);
-
-            match (op, lhs, rhs) {
-                ('+', Value::Number(a), Value::Number(b)) => Value::Number(a + b),
-                ('-', Value::Number(a), Value::Number(b)) => Value::Number(a - b),
-                ('/', Value::Number(a), Value::Number(b)) => Value::Number(a / b),
-                ('*', Value::Number(a), Value::Number(b)) => Value::Number(a * b),
-                ('<', Value::Number(a), Value::Number(b)) => Value::Bool(a < b),
-                ('>', Value::Number(a), Value::Number(b)) => Value::Bool(a > b),
-                ('≤', Value::Number(a), Value::Number(b)) => Value::Bool(a <= b),
-                ('≥', Value::Number(a), Value::Number(b)) => Value::Bool(a >= b),
-                ('=', a, b) => Value::Bool(a == b),
-                ('!', a, b) => Value::Bool(a != b),
-                ('&', Value::Bool(a), Value::Bool(b)) => Value::Bool(a && b),
-                ('|', Value::Bool(a), Value::Bool(b)) => Value::Bool(a || b),
-                (op, lhs, rhs) => panic!("unsupported {:?} {} {:?}", lhs, op, rhs),
-            }
+            let lhs = eval_expression(&**lhs, component_type, eval_context)?;
+            let rhs = eval_expression(&**rhs, component_type, eval_context)?;
+            eval_binary_op(*op, lhs, rhs)
         }
         Expression::UnaryOp { sub, op } => {
-            let sub = eval_expression(&**sub, component_type, eval_context);
+            let sub = eval_expression(&**sub, component_type, eval_context)?;
             match (sub, op) {
-                (Value::Number(a), '+') => Value::Number(a),
-                (Value::Number(a), '-') => Value::Number(-a),
-                (Value::Bool(a), '!') => Value::Bool(!a),
-                (sub, op) => panic!("unsupported {} {:?}", op, sub),
+                (Value::Number(a), '+') => Ok(Value::Number(a)),
+                (Value::Number(a), '-') => Ok(Value::Number(-a)),
+                (Value::Bool(a), '!') => Ok(Value::Bool(!a)),
+                (sub, op) => {
+                    let expected = if op == '!' { Type::Bool } else { Type::Float32 };
+                    let got = value_type(&sub);
+                    Err(EvalError::type_mismatch(None, expected, got)
+                        .with_message(format!("unsupported {} {:?}", op, sub)))
+                }
             }
         }
         Expression::ResourceReference { absolute_source_path } => {
-            Value::Resource(Resource::AbsoluteFilePath(absolute_source_path.as_str().into()))
+            Ok(Value::Resource(Resource::AbsoluteFilePath(absolute_source_path.as_str().into())))
         }
         Expression::Condition { condition, true_expr, false_expr } => {
-            match eval_expression(&**condition, component_type, eval_context).try_into()
-                as Result<bool, _>
-            {
+            let condition_value = eval_expression(&**condition, component_type, eval_context)?;
+            let got = value_type(&condition_value);
+            match condition_value.try_into() as Result<bool, _> {
                 Ok(true) => eval_expression(&**true_expr, component_type, eval_context),
                 Ok(false) => eval_expression(&**false_expr, component_type, eval_context),
-                _ => panic!("conditional expression did not evaluate to boolean"),
+                Err(_) => Err(EvalError::type_mismatch(None, Type::Bool, got)
+                    .with_message("conditional expression did not evaluate to a boolean")),
             }
         }
-        Expression::Array { values, .. } => Value::Array(
-            values.iter().map(|e| eval_expression(e, component_type, eval_context)).collect(),
-        ),
-        Expression::Object { values, .. } => Value::Object(
+        Expression::Array { values, .. } => Ok(Value::Array(
             values
                 .iter()
-                .map(|(k, v)| (k.clone(), eval_expression(v, component_type, eval_context)))
-                .collect(),
-        ),
-        Expression::PathElements { elements } => {
-            Value::PathElements(PathElements::SharedElements(sixtyfps_corelib::SharedArray::<
+                .map(|e| eval_expression(e, component_type, eval_context))
+                .collect::<Result<_, _>>()?,
+        )),
+        Expression::Object { values, .. } => Ok(Value::Object(
+            values
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), eval_expression(v, component_type, eval_context)?)))
+                .collect::<Result<_, EvalError>>()?,
+        )),
+        Expression::PathElements { elements } => Ok(Value::PathElements(
+            PathElements::SharedElements(sixtyfps_corelib::SharedArray::<
                 sixtyfps_corelib::abi::datastructures::PathElement,
             >::from_iter(
                 elements
                     .iter()
-                    .map(|element| convert_path_element(element, component_type, eval_context)),
-            )))
-        }
+                    .map(|element| convert_path_element(element, component_type, eval_context))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter(),
+            )),
+        )),
     }
 }
 
@@ -358,32 +630,538 @@ pub fn new_struct_with_bindings<
     bindings: &HashMap<String, Expression>,
     component_type: &crate::ComponentDescription,
     eval_context: &corelib::EvaluationContext,
-) -> ElementType {
+) -> Result<ElementType, EvalError> {
     let mut element = ElementType::default();
     for (prop, info) in ElementType::fields::<Value>().into_iter() {
         if let Some(binding) = &bindings.get(prop) {
-            let value = eval_expression(&binding, &*component_type, &eval_context);
-            info.set_field(&mut element, value).unwrap();
+            let value = eval_expression(&binding, &*component_type, &eval_context)?;
+            let got = value_type(&value);
+            info.set_field(&mut element, value).map_err(|_| {
+                EvalError::new(format!(
+                    "binding for field `{}` evaluated to a value of the wrong type ({:?})",
+                    prop, got
+                ))
+            })?;
         }
     }
-    element
+    Ok(element)
 }
 
 fn convert_path_element(
     expr_element: &ExprPathElement,
     component_type: &crate::ComponentDescription,
     eval_context: &corelib::EvaluationContext,
-) -> sixtyfps_corelib::abi::datastructures::PathElement {
+) -> Result<sixtyfps_corelib::abi::datastructures::PathElement, EvalError> {
     match expr_element.element_type.class_name.as_str() {
-        "LineTo" => sixtyfps_corelib::abi::datastructures::PathElement::LineTo(
-            new_struct_with_bindings(&expr_element.bindings, component_type, eval_context),
-        ),
-        "ArcTo" => sixtyfps_corelib::abi::datastructures::PathElement::ArcTo(
-            new_struct_with_bindings(&expr_element.bindings, component_type, eval_context),
-        ),
-        _ => panic!(
-            "Cannot create unsupported path element {}",
+        "LineTo" => Ok(sixtyfps_corelib::abi::datastructures::PathElement::LineTo(
+            new_struct_with_bindings(&expr_element.bindings, component_type, eval_context)?,
+        )),
+        "ArcTo" => Ok(sixtyfps_corelib::abi::datastructures::PathElement::ArcTo(
+            new_struct_with_bindings(&expr_element.bindings, component_type, eval_context)?,
+        )),
+        _ => Err(EvalError::new(format!(
+            "cannot create unsupported path element {}",
             expr_element.element_type.class_name
-        ),
+        ))),
+    }
+}
+
+/// A compiled, reusable binding: given the [`EvaluationContext`] of a live
+/// component instance, produces the current value without re-walking the
+/// `Expression` tree or re-resolving any `NamedReference`.
+pub type CompiledExpression = Box<dyn Fn(&corelib::EvaluationContext) -> Result<Value, EvalError>>;
+
+/// Compiles `e` into a [`CompiledExpression`] closure.
+///
+/// Property bindings are re-evaluated every time one of their dependencies
+/// changes, yet [`eval_expression`] re-matches the whole `Expression` AST and
+/// re-resolves every `NamedReference`/`custom_properties`/`items` lookup on
+/// every single call. `compile_expression` performs that structural work
+/// *once*: it walks `e` exactly as `eval_expression` would, but instead of
+/// producing a `Value` it captures the resolved offsets, property tables and
+/// child closures into a tree of closures, so that the hot reactive-update
+/// path only runs closures and arithmetic for literals, casts, unary and
+/// binary operators, conditions and property references local to
+/// `component_type`. A reference to a property of an ancestor component
+/// still walks `parent_context` and looks the property up on every call, and
+/// every other variant is interpreted with `eval_expression` on every call.
+///
+/// `component_type` is `'static` (every `ComponentDescription` outlives the
+/// component instances built from it) so that the local `PropertyReference`
+/// fast path below can capture the resolved `ErasedPropertyInfo`/offset
+/// directly instead of re-deriving `component_type` and re-hashing
+/// `custom_properties`/`items` on every call. [`install_compiled_binding`]
+/// is what actually stores the closure returned here as a property's
+/// binding, rather than it being re-invoked on the original `Expression`
+/// each time.
+pub fn compile_expression(
+    e: &Expression,
+    component_type: &'static crate::ComponentDescription,
+) -> CompiledExpression {
+    match e {
+        Expression::StringLiteral(s) => {
+            let value = Value::String(s.as_str().into());
+            Box::new(move |_| Ok(value.clone()))
+        }
+        Expression::NumberLiteral(n) => {
+            let value = Value::Number(*n);
+            Box::new(move |_| Ok(value.clone()))
+        }
+        Expression::BoolLiteral(b) => {
+            let value = Value::Bool(*b);
+            Box::new(move |_| Ok(value.clone()))
+        }
+        Expression::PropertyReference(NamedReference { element, name }) => {
+            // Whether `element` lives in the instance `component_type` itself
+            // describes (as opposed to some ancestor, reached by following
+            // `parent_context`) only depends on the static component tree, so
+            // it can be decided once here rather than on every evaluation.
+            let is_local = Rc::ptr_eq(
+                &element.upgrade().unwrap().borrow().enclosing_component.upgrade().unwrap(),
+                &component_type.original,
+            );
+            if is_local {
+                let is_root = {
+                    let el = element.upgrade().unwrap();
+                    let el = el.borrow();
+                    el.id == el.enclosing_component.upgrade().unwrap().root_element.borrow().id
+                };
+                if is_root {
+                    if let Some(x) = component_type.custom_properties.get(name.as_str()) {
+                        // `component_type` is `'static`, so `x` (a reference into
+                        // its `custom_properties` map) and its offset/prop can be
+                        // captured directly: no hashing and no re-deriving
+                        // `component_type` on every call.
+                        let offset = x.offset;
+                        let prop = x.prop;
+                        return Box::new(move |ctx: &corelib::EvaluationContext| {
+                            let component_mem = ctx.component.as_ptr();
+                            Ok(unsafe {
+                                prop.get(Pin::new_unchecked(&*component_mem.add(offset)), ctx)
+                                    .unwrap()
+                            })
+                        });
+                    }
+                }
+                let id = element.upgrade().unwrap().borrow().id.clone();
+                let item_info = &component_type.items[id.as_str()];
+                let prop = item_info.rtti.properties[name.as_str()];
+                Box::new(move |ctx: &corelib::EvaluationContext| {
+                    let component_mem = ctx.component.as_ptr();
+                    let item = unsafe { item_info.item_from_component(component_mem) };
+                    Ok(prop.get(item, ctx))
+                })
+            } else {
+                // `element` lives in an ancestor component; how many
+                // `parent_context` hops that takes depends on which instance
+                // is live (repeated components can be nested dynamically), so
+                // this falls back to the same walk `eval_expression` does.
+                let element = element.clone();
+                let name = name.clone();
+                Box::new(move |ctx| {
+                    let element = element.upgrade().unwrap();
+                    let (component_mem, component_type, ctx) =
+                        enclosing_component_for_element(&element, ctx);
+                    let el = element.borrow();
+                    if el.id == el.enclosing_component.upgrade().unwrap().root_element.borrow().id
+                    {
+                        if let Some(x) = component_type.custom_properties.get(name.as_str()) {
+                            return Ok(unsafe {
+                                x.prop
+                                    .get(
+                                        Pin::new_unchecked(&*component_mem.add(x.offset)),
+                                        ctx,
+                                    )
+                                    .unwrap()
+                            });
+                        }
+                    }
+                    let item_info = &component_type.items[el.id.as_str()];
+                    core::mem::drop(el);
+                    let item = unsafe { item_info.item_from_component(component_mem) };
+                    Ok(item_info.rtti.properties[name.as_str()].get(item, ctx))
+                })
+            }
+        }
+        Expression::Cast { from, to } => {
+            let from = compile_expression(from, component_type);
+            let to = to.clone();
+            Box::new(move |ctx| {
+                let v = from(ctx)?;
+                Ok(match (v, &to) {
+                    (Value::Number(n), Type::Int32) => Value::Number(n.round()),
+                    (Value::Number(n), Type::String) => {
+                        Value::String(SharedString::from(format!("{}", n).as_str()))
+                    }
+                    (Value::Number(n), Type::Color) => Value::Color(Color::from(n as u32)),
+                    (v, _) => v,
+                })
+            })
+        }
+        Expression::UnaryOp { sub, op } => {
+            let sub = compile_expression(sub, component_type);
+            let op = *op;
+            Box::new(move |ctx| {
+                let sub = sub(ctx)?;
+                match (sub, op) {
+                    (Value::Number(a), '+') => Ok(Value::Number(a)),
+                    (Value::Number(a), '-') => Ok(Value::Number(-a)),
+                    (Value::Bool(a), '!') => Ok(Value::Bool(!a)),
+                    (sub, op) => Err(EvalError::new(format!("unsupported {} {:?}", op, sub))),
+                }
+            })
+        }
+        Expression::BinaryExpression { lhs, rhs, op } => {
+            let lhs = compile_expression(lhs, component_type);
+            let rhs = compile_expression(rhs, component_type);
+            let op = *op;
+            // The operator is known at compile time, so only its two operands
+            // need to be evaluated here; dispatch reuses `eval_binary_op` so
+            // this stays in lock-step with `eval_expression`.
+            Box::new(move |ctx| eval_binary_op(op, lhs(ctx)?, rhs(ctx)?))
+        }
+        Expression::Condition { condition, true_expr, false_expr } => {
+            let condition = compile_expression(condition, component_type);
+            let true_expr = compile_expression(true_expr, component_type);
+            let false_expr = compile_expression(false_expr, component_type);
+            Box::new(move |ctx| match condition(ctx)?.try_into() as Result<bool, _> {
+                Ok(true) => true_expr(ctx),
+                Ok(false) => false_expr(ctx),
+                Err(_) => Err(EvalError::new("conditional expression did not evaluate to a boolean")),
+            })
+        }
+        // The remaining variants (control flow, signal calls, array/object/path
+        // construction, ...) are not yet worth a dedicated closure shape; fall
+        // back to interpreting the original `Expression` each time, still
+        // behind the same `CompiledExpression` signature so callers don't need
+        // to know which path a given binding takes.
+        other => {
+            let e = other.clone();
+            Box::new(move |ctx| {
+                // The `ComponentDescription` is looked up again from the live
+                // instance on each call, the same way
+                // `enclosing_component_for_element` does.
+                let component_type = unsafe { crate::dynamic_component::get_component_type(ctx.component) };
+                eval_expression(&e, component_type, ctx)
+            })
+        }
+    }
+}
+
+/// Compiles `e` once and installs the result as `prop`'s binding on `item`,
+/// so that subsequent reactive re-evaluations run the compiled closure
+/// instead of `eval_expression` walking `e` from scratch every time. This is
+/// the call site `set_binding` call sites should go through once a binding's
+/// `Expression` is known, rather than wrapping `eval_expression` in a closure
+/// directly.
+///
+/// `ErasedPropertyInfo::set_binding` predates `EvalError` and still expects
+/// an infallible `Value`-returning closure; an evaluation error falls back to
+/// `Value::default()` (`Value::Void`) rather than panicking the property
+/// system.
+pub fn install_compiled_binding(
+    prop: &dyn ErasedPropertyInfo,
+    item: Pin<ItemRef>,
+    e: &Expression,
+    component_type: &'static crate::ComponentDescription,
+    animation: Option<PropertyAnimation>,
+) {
+    let compiled = compile_expression(e, component_type);
+    prop.set_binding(item, Box::new(move |ctx| compiled(ctx).unwrap_or_default()), animation);
+}
+
+impl crate::ComponentDescription {
+    /// Reads every `custom_properties` entry of a live component instance
+    /// into a single `Value::Object`, keyed by property name.
+    ///
+    /// Together with [`Self::apply_properties_from_value`] this allows whole-
+    /// component state to be saved and restored: test fixtures can assert on
+    /// the serialized `Value`, and inspector tooling can snapshot/replay a
+    /// running component without knowing its properties ahead of time.
+    pub fn properties_to_value(&self, eval_context: &corelib::EvaluationContext) -> Value {
+        let component_mem = eval_context.component.as_ptr();
+        Value::Object(
+            self.custom_properties
+                .iter()
+                .map(|(name, x)| {
+                    let v = unsafe {
+                        x.prop
+                            .get(Pin::new_unchecked(&*component_mem.add(x.offset)), eval_context)
+                            .unwrap()
+                    };
+                    (name.clone(), v)
+                })
+                .collect(),
+        )
+    }
+
+    /// The inverse of [`Self::properties_to_value`]: applies each entry of
+    /// `values` back onto the matching `custom_properties` of a live
+    /// component instance. Unknown keys are ignored rather than treated as an
+    /// error, so that a snapshot taken from a newer/older version of the
+    /// component can still be partially restored.
+    pub fn apply_properties_from_value(
+        &self,
+        eval_context: &corelib::EvaluationContext,
+        values: &Value,
+    ) {
+        let component_mem = eval_context.component.as_ptr();
+        if let Value::Object(map) = values {
+            for (name, value) in map {
+                if let Some(x) = self.custom_properties.get(name) {
+                    unsafe {
+                        let p = Pin::new_unchecked(&*component_mem.add(x.offset));
+                        let _ = x.prop.set(p, value.clone(), None);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `serde` support for [`Value`], used for whole-component state snapshots
+/// (see [`crate::ComponentDescription::properties_to_value`]), test fixtures
+/// that assert on serialized values, and inspector tooling. Enabled by the
+/// `serde` feature so that consumers who don't need it don't pay for the
+/// dependency.
+///
+/// Each variant is wire-encoded as a single-entry map naming it (e.g.
+/// `{"Color": "#rrggbbaa"}`, `{"PathElements": [...]}`), rather than trying
+/// to infer the variant from the shape of a bare JSON value: `Value::String`
+/// and `Value::Color` both serialize to a bare string and `Value::Array` and
+/// `Value::PathElements` both serialize to a JSON array, so guessing from
+/// shape alone is ambiguous and silently changes a value's type on a
+/// round-trip. Naming the variant removes that ambiguity, at the cost of not
+/// reading as plain JSON primitives on the wire.
+#[cfg(feature = "serde")]
+mod value_serde {
+    use super::*;
+    use sixtyfps_corelib::abi::datastructures::PathElement;
+    use serde::de::{Deserializer, Error as _, MapAccess, Visitor};
+    use serde::ser::{SerializeMap, SerializeSeq, Serializer};
+
+    fn color_to_hex(c: &Color) -> String {
+        let argb: u32 = (*c).into();
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            (argb >> 16) & 0xff,
+            (argb >> 8) & 0xff,
+            argb & 0xff,
+            (argb >> 24) & 0xff,
+        )
+    }
+
+    fn hex_to_color(s: &str) -> Option<Color> {
+        let s = s.strip_prefix('#')?;
+        if s.len() != 8 || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let r = u32::from_str_radix(&s[0..2], 16).ok()?;
+        let g = u32::from_str_radix(&s[2..4], 16).ok()?;
+        let b = u32::from_str_radix(&s[4..6], 16).ok()?;
+        let a = u32::from_str_radix(&s[6..8], 16).ok()?;
+        Some(Color::from((a << 24) | (r << 16) | (g << 8) | b))
+    }
+
+    /// Reads every field `T::fields::<Value>()` knows about off of `item`,
+    /// the same reflection `new_struct_with_bindings` uses to write them.
+    fn builtin_item_to_value<T: corelib::rtti::BuiltinItem>(item: &T) -> Value {
+        Value::Object(
+            T::fields::<Value>()
+                .into_iter()
+                .filter_map(|(name, info)| {
+                    info.get_field(item).ok().map(|v| (name.to_string(), v))
+                })
+                .collect(),
+        )
+    }
+
+    /// The inverse of [`builtin_item_to_value`]: writes every field present in
+    /// `fields` onto a freshly-`Default`-constructed `T`.
+    fn value_to_builtin_item<T: Default + corelib::rtti::BuiltinItem>(
+        fields: &HashMap<String, Value>,
+    ) -> T {
+        let mut item = T::default();
+        for (name, info) in T::fields::<Value>().into_iter() {
+            if let Some(v) = fields.get(name) {
+                let _ = info.set_field(&mut item, v.clone());
+            }
+        }
+        item
+    }
+
+    /// Encodes a single `PathElement` as `{"type": "LineTo"|"ArcTo", "fields": {...}}`,
+    /// so it round-trips losslessly.
+    fn path_element_to_value(elem: &PathElement) -> Value {
+        let (tag, fields) = match elem {
+            PathElement::LineTo(e) => ("LineTo", builtin_item_to_value(e)),
+            PathElement::ArcTo(e) => ("ArcTo", builtin_item_to_value(e)),
+        };
+        let mut obj = HashMap::new();
+        obj.insert("type".to_string(), Value::String(tag.into()));
+        obj.insert("fields".to_string(), fields);
+        Value::Object(obj)
+    }
+
+    fn value_to_path_element(v: &Value) -> Option<PathElement> {
+        let obj = if let Value::Object(obj) = v { obj } else { return None };
+        let tag = match obj.get("type") {
+            Some(Value::String(s)) => s.as_str(),
+            _ => return None,
+        };
+        let fields = match obj.get("fields") {
+            Some(Value::Object(fields)) => fields,
+            _ => return None,
+        };
+        match tag {
+            "LineTo" => Some(PathElement::LineTo(value_to_builtin_item(fields))),
+            "ArcTo" => Some(PathElement::ArcTo(value_to_builtin_item(fields))),
+            _ => None,
+        }
+    }
+
+    fn path_elements_to_values(pe: &PathElements) -> Vec<Value> {
+        match pe {
+            PathElements::SharedElements(arr) => {
+                arr.iter().map(path_element_to_value).collect()
+            }
+        }
+    }
+
+    fn values_to_path_elements(values: &[Value]) -> PathElements {
+        PathElements::SharedElements(sixtyfps_corelib::SharedArray::from_iter(
+            values.iter().filter_map(value_to_path_element),
+        ))
+    }
+
+    impl serde::Serialize for Value {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut m = serializer.serialize_map(Some(1))?;
+            match self {
+                Value::Void => m.serialize_entry("Void", &())?,
+                Value::Number(n) => m.serialize_entry("Number", n)?,
+                Value::String(s) => m.serialize_entry("String", s.as_str())?,
+                Value::Bool(b) => m.serialize_entry("Bool", b)?,
+                Value::Color(c) => m.serialize_entry("Color", &color_to_hex(c))?,
+                Value::Resource(Resource::AbsoluteFilePath(path)) => {
+                    m.serialize_entry("Resource", path.as_str())?
+                }
+                Value::Resource(_) => m.serialize_entry("Resource", &Option::<String>::None)?,
+                Value::Array(items) => m.serialize_entry("Array", items)?,
+                Value::Object(map) => m.serialize_entry("Object", map)?,
+                Value::PathElements(pe) => {
+                    m.serialize_entry("PathElements", &path_elements_to_values(pe))?
+                }
+            }
+            m.end()
+        }
+    }
+
+    struct ValueVisitor;
+
+    impl<'de> Visitor<'de> for ValueVisitor {
+        type Value = Value;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a Value, encoded as a single-entry map naming its variant")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+            let key: String =
+                map.next_key()?.ok_or_else(|| A::Error::custom("expected a Value variant tag"))?;
+            Ok(match key.as_str() {
+                "Void" => {
+                    let _: () = map.next_value()?;
+                    Value::Void
+                }
+                "Number" => Value::Number(map.next_value()?),
+                "String" => Value::String(SharedString::from(map.next_value::<String>()?.as_str())),
+                "Bool" => Value::Bool(map.next_value()?),
+                "Color" => {
+                    let hex: String = map.next_value()?;
+                    hex_to_color(&hex)
+                        .map(Value::Color)
+                        .ok_or_else(|| A::Error::custom(format!("invalid color {:?}", hex)))?
+                }
+                "Resource" => match map.next_value::<Option<String>>()? {
+                    Some(path) => Value::Resource(Resource::AbsoluteFilePath(path.into())),
+                    None => Value::Resource(Resource::None),
+                },
+                "Array" => Value::Array(map.next_value()?),
+                "Object" => Value::Object(map.next_value()?),
+                "PathElements" => {
+                    Value::PathElements(values_to_path_elements(&map.next_value::<Vec<Value>>()?))
+                }
+                other => return Err(A::Error::unknown_variant(other, VARIANTS)),
+            })
+        }
+    }
+
+    const VARIANTS: &[&str] = &[
+        "Void",
+        "Number",
+        "String",
+        "Bool",
+        "Color",
+        "Resource",
+        "Array",
+        "Object",
+        "PathElements",
+    ];
+
+    impl<'de> serde::Deserialize<'de> for Value {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Value, D::Error> {
+            deserializer.deserialize_map(ValueVisitor)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn roundtrip(v: &Value) -> Value {
+            let json = serde_json::to_string(v).unwrap();
+            serde_json::from_str(&json).unwrap()
+        }
+
+        #[test]
+        fn string_that_looks_like_a_color_stays_a_string() {
+            // A color and a string both carry a plain JSON string, so
+            // without the variant tag this value, which parses as a hex
+            // color, could come back as Value::Color on the way in.
+            let v = Value::String("#11223344".into());
+            assert_eq!(roundtrip(&v), v);
+        }
+
+        #[test]
+        fn color_round_trips() {
+            let v = Value::Color(Color::from(0x11223344u32));
+            assert_eq!(roundtrip(&v), v);
+        }
+
+        #[test]
+        fn array_round_trips_and_is_not_confused_with_path_elements() {
+            // Same shape-guessing hazard as the Color/String case: both
+            // encode to a JSON array, so without the variant tag one would
+            // silently decode as the other.
+            let v = Value::Array(vec![Value::Number(1.0), Value::String("a".into())]);
+            assert_eq!(roundtrip(&v), v);
+
+            let pe = Value::PathElements(values_to_path_elements(&[path_element_to_value(
+                &PathElement::LineTo(Default::default()),
+            )]));
+            assert_eq!(roundtrip(&pe), pe);
+            assert_ne!(roundtrip(&pe), v);
+        }
+
+        #[test]
+        fn path_elements_round_trip() {
+            let pe = values_to_path_elements(&[
+                path_element_to_value(&PathElement::LineTo(Default::default())),
+                path_element_to_value(&PathElement::ArcTo(Default::default())),
+            ]);
+            let v = Value::PathElements(pe);
+            assert_eq!(roundtrip(&v), v);
+        }
     }
 }
@@ -0,0 +1,529 @@
+//! An interactive expression REPL bound to a live component instance.
+//!
+//! Lets a developer type `.60` expressions against a running component and
+//! see the resulting [`Value`](crate::eval::Value), without recompiling: the
+//! REPL resolves `PropertyReference`s and callbacks by name through the same
+//! `custom_properties`/`custom_signals`/`items` tables `eval_expression`
+//! already uses, and reuses the span-aware [`EvalError`](crate::eval::EvalError)
+//! reporting so a syntax or evaluation error is shown inline instead of
+//! aborting the session.
+//!
+//! The compiler's own expression parser produces `Expression` trees whose
+//! identifiers are only resolved against a live element/symbol table during
+//! the compiler's own lowering pass, which this tree doesn't have access to.
+//! A typed-in identifier therefore has to be resolved by hand, against the
+//! live `ComponentDescription`, before `eval_expression` ever sees it: this
+//! module's own small expression parser (see [`parse_expression`]) does that
+//! resolution itself as it parses, rather than handing an unresolved
+//! identifier straight to `eval_expression` (which would just reject it as
+//! `Expression::Uncompiled`).
+
+use crate::eval::{eval_expression, EvalError, Span, Value};
+use sixtyfps_compilerlib::expression_tree::{Expression, NamedReference};
+use sixtyfps_compilerlib::object_tree::ElementRc;
+use sixtyfps_corelib::EvaluationContext;
+use std::rc::Rc;
+
+/// One REPL session, bound to a single live component instance.
+///
+/// Feed it input line by line with [`Repl::feed_line`]: it buffers lines
+/// until braces/parens/brackets balance, then parses and evaluates the
+/// accumulated buffer and returns its result. The parser accepts parentheses
+/// for grouping but no `{ }` blocks, `[ ]` arrays or `,`, so a balanced buffer
+/// containing those is reported as an error.
+pub struct Repl<'a> {
+    component_type: &'a crate::ComponentDescription,
+    eval_context: &'a EvaluationContext<'a>,
+    /// Every expression successfully evaluated so far, oldest first.
+    history: Vec<String>,
+    /// Input accumulated while waiting for balanced delimiters.
+    pending: String,
+}
+
+/// The result of feeding one line of input to the REPL.
+pub enum ReplOutcome {
+    /// The line didn't yet form a complete expression; more input is needed.
+    NeedMoreInput,
+    /// A complete expression was parsed and evaluated.
+    Value(Value),
+    /// Parsing or evaluation failed; `render()` gives an ariadne-style report.
+    Error(EvalError),
+}
+
+impl<'a> Repl<'a> {
+    pub fn new(
+        component_type: &'a crate::ComponentDescription,
+        eval_context: &'a EvaluationContext<'a>,
+    ) -> Self {
+        Self { component_type, eval_context, history: Vec::new(), pending: String::new() }
+    }
+
+    /// Previously evaluated expressions, oldest first.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Feeds one line of input into the REPL.
+    pub fn feed_line(&mut self, line: &str) -> ReplOutcome {
+        if !self.pending.is_empty() {
+            self.pending.push('\n');
+        }
+        self.pending.push_str(line);
+
+        if !is_balanced(&self.pending) {
+            return ReplOutcome::NeedMoreInput;
+        }
+
+        let source = std::mem::take(&mut self.pending);
+        let trimmed = source.trim();
+        if trimmed.is_empty() {
+            return ReplOutcome::NeedMoreInput;
+        }
+
+        // `Expression` carries no span of its own, so the whole typed line is
+        // the best span available; attach it only as a fallback, in case a
+        // more specific one is ever produced upstream.
+        let whole_line_span = || Span {
+            file: None,
+            source: Rc::from(trimmed),
+            offset: 0,
+            len: trimmed.len(),
+        };
+
+        match parse_expression(trimmed, self.component_type) {
+            Ok(expr) => match eval_expression(&expr, self.component_type, self.eval_context) {
+                Ok(value) => {
+                    self.history.push(trimmed.to_string());
+                    ReplOutcome::Value(value)
+                }
+                Err(err) => ReplOutcome::Error(err.with_fallback_span(whole_line_span())),
+            },
+            Err(err) => ReplOutcome::Error(err.with_fallback_span(whole_line_span())),
+        }
+    }
+}
+
+/// Whether `source`'s braces/parens/brackets are balanced, ignoring any that
+/// occur inside a string literal. Used to decide whether the REPL should keep
+/// reading more lines before attempting to parse, mirroring how a `.60`
+/// editor would wait for a block to close.
+fn is_balanced(source: &str) -> bool {
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in source.chars() {
+        if in_string {
+            match c {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => in_string = false,
+                _ => escaped = false,
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    depth <= 0 && !in_string
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    String(String),
+    Ident(String),
+    Op(char),
+    LParen,
+    RParen,
+    Dot,
+}
+
+/// Splits `source` into [`Token`]s. Two-character operators (`==`, `!=`,
+/// `<=`, `>=`) are folded into the single-`char` encoding `eval_binary_op`
+/// already uses (`=`, `!`, `≤`, `≥`) so the resulting `Expression` is exactly
+/// what `eval_expression` expects.
+fn tokenize(source: &str) -> Result<Vec<Token>, EvalError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '.' => {
+                tokens.push(Token::Dot);
+                i += 1;
+            }
+            '"' => {
+                let mut s = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(EvalError::new("unterminated string literal")),
+                        Some('"') => {
+                            i += 1;
+                            break;
+                        }
+                        Some('\\') => {
+                            i += 1;
+                            match chars.get(i) {
+                                Some('n') => s.push('\n'),
+                                Some('t') => s.push('\t'),
+                                Some(other) => s.push(*other),
+                                None => return Err(EvalError::new("unterminated string literal")),
+                            }
+                            i += 1;
+                        }
+                        Some(other) => {
+                            s.push(*other);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(Token::String(s));
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while chars.get(i).map_or(false, |c| c.is_ascii_digit() || *c == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let n = text
+                    .parse::<f64>()
+                    .map_err(|_| EvalError::new(format!("invalid number literal `{}`", text)))?;
+                tokens.push(Token::Number(n));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while chars.get(i).map_or(false, |c| c.is_alphanumeric() || *c == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+            '=' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op('='));
+                i += 2;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op('!'));
+                i += 2;
+            }
+            '<' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op('≤'));
+                i += 2;
+            }
+            '>' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op('≥'));
+                i += 2;
+            }
+            '+' | '-' | '*' | '/' | '%' | '<' | '>' | '&' | '|' | '!' => {
+                tokens.push(Token::Op(c));
+                i += 1;
+            }
+            other => return Err(EvalError::new(format!("unexpected character `{}`", other))),
+        }
+    }
+    Ok(tokens)
+}
+
+/// A tiny recursive-descent parser that builds an `Expression` tree directly,
+/// resolving every bare identifier it encounters against `component_type`'s
+/// `custom_properties`/`custom_signals`/`items` tables as it goes (see
+/// [`resolve_name`]), rather than leaving name resolution to a later pass
+/// that doesn't exist here.
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    component_type: &'a crate::ComponentDescription,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let t = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        t
+    }
+
+    fn expect_op(&mut self, ops: &[char]) -> Option<char> {
+        if let Some(Token::Op(c)) = self.peek() {
+            if ops.contains(c) {
+                let c = *c;
+                self.pos += 1;
+                return Some(c);
+            }
+        }
+        None
+    }
+
+    fn parse_expr(&mut self) -> Result<Expression, EvalError> {
+        self.parse_or()
+    }
+
+    fn parse_or(&mut self) -> Result<Expression, EvalError> {
+        let mut lhs = self.parse_and()?;
+        while self.expect_op(&['|']).is_some() {
+            let rhs = self.parse_and()?;
+            lhs = Expression::BinaryExpression { lhs: Box::new(lhs), rhs: Box::new(rhs), op: '|' };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expression, EvalError> {
+        let mut lhs = self.parse_equality()?;
+        while self.expect_op(&['&']).is_some() {
+            let rhs = self.parse_equality()?;
+            lhs = Expression::BinaryExpression { lhs: Box::new(lhs), rhs: Box::new(rhs), op: '&' };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_equality(&mut self) -> Result<Expression, EvalError> {
+        let mut lhs = self.parse_comparison()?;
+        while let Some(op) = self.expect_op(&['=', '!']) {
+            let rhs = self.parse_comparison()?;
+            lhs = Expression::BinaryExpression { lhs: Box::new(lhs), rhs: Box::new(rhs), op };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expression, EvalError> {
+        let mut lhs = self.parse_additive()?;
+        while let Some(op) = self.expect_op(&['<', '>', '≤', '≥']) {
+            let rhs = self.parse_additive()?;
+            lhs = Expression::BinaryExpression { lhs: Box::new(lhs), rhs: Box::new(rhs), op };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_additive(&mut self) -> Result<Expression, EvalError> {
+        let mut lhs = self.parse_term()?;
+        while let Some(op) = self.expect_op(&['+', '-']) {
+            let rhs = self.parse_term()?;
+            lhs = Expression::BinaryExpression { lhs: Box::new(lhs), rhs: Box::new(rhs), op };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_term(&mut self) -> Result<Expression, EvalError> {
+        let mut lhs = self.parse_unary()?;
+        while let Some(op) = self.expect_op(&['*', '/', '%']) {
+            let rhs = self.parse_unary()?;
+            lhs = Expression::BinaryExpression { lhs: Box::new(lhs), rhs: Box::new(rhs), op };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, EvalError> {
+        if let Some(op) = self.expect_op(&['+', '-', '!']) {
+            let sub = self.parse_unary()?;
+            return Ok(Expression::UnaryOp { sub: Box::new(sub), op });
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, EvalError> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expression::NumberLiteral(n)),
+            Some(Token::String(s)) => Ok(Expression::StringLiteral(s)),
+            Some(Token::LParen) => {
+                let e = self.parse_expr()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(e),
+                    _ => Err(EvalError::new("expected `)`")),
+                }
+            }
+            Some(Token::Ident(name)) => {
+                if name == "true" {
+                    return Ok(Expression::BoolLiteral(true));
+                }
+                if name == "false" {
+                    return Ok(Expression::BoolLiteral(false));
+                }
+                // A bare identifier may be qualified with one `.segment`
+                // (`item_id.property`), otherwise it names a root-level
+                // property/signal; either way it's resolved right here
+                // against the live component, not left for eval_expression
+                // to choke on as `Expression::Uncompiled`.
+                let mut path = vec![name];
+                while matches!(self.peek(), Some(Token::Dot)) {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Ident(segment)) => path.push(segment),
+                        _ => return Err(EvalError::new("expected an identifier after `.`")),
+                    }
+                }
+                let is_call = matches!(self.peek(), Some(Token::LParen))
+                    && matches!(self.tokens.get(self.pos + 1), Some(Token::RParen));
+                if is_call {
+                    self.pos += 2;
+                }
+                resolve_name(self.component_type, &path, is_call)
+            }
+            other => Err(EvalError::new(format!("unexpected token {:?}", other))),
+        }
+    }
+}
+
+/// Resolves a dotted identifier path (`["prop"]` for a root-level name, or
+/// `["item_id", "prop"]` for an element-qualified one) against
+/// `component_type`'s `custom_properties`/`custom_signals`/`items` tables,
+/// producing a `NamedReference`-based `Expression` exactly like the ones
+/// `eval_expression`'s `PropertyReference`/`SignalReference` arms expect.
+fn resolve_name(
+    component_type: &crate::ComponentDescription,
+    path: &[String],
+    is_call: bool,
+) -> Result<Expression, EvalError> {
+    let root_element = component_type.original.root_element.borrow().clone();
+    let (element, name) = match path {
+        [name] => (root_element, name.clone()),
+        [id, name] => {
+            let element = find_element_by_id(&root_element, id).ok_or_else(|| {
+                EvalError::new(format!("no element named `{}` in this component", id))
+            })?;
+            (element, name.clone())
+        }
+        _ => return Err(EvalError::new("expected `name` or `element.name`")),
+    };
+
+    let known = {
+        let el = element.borrow();
+        if Rc::ptr_eq(&element, &root_element) {
+            if is_call {
+                component_type.custom_signals.contains_key(name.as_str())
+            } else {
+                component_type.custom_properties.contains_key(name.as_str())
+            }
+        } else if let Some(item_info) = component_type.items.get(el.id.as_str()) {
+            if is_call {
+                item_info.rtti.signals.contains_key(name.as_str())
+            } else {
+                item_info.rtti.properties.contains_key(name.as_str())
+            }
+        } else {
+            false
+        }
+    };
+    if !known {
+        return Err(EvalError::new(format!(
+            "no {} named `{}` on this component",
+            if is_call { "callback" } else { "property" },
+            name
+        )));
+    }
+
+    let reference = NamedReference { element: Rc::downgrade(&element), name };
+    if is_call {
+        Ok(Expression::FunctionCall {
+            function: Box::new(Expression::SignalReference(reference)),
+            arguments: Vec::new(),
+        })
+    } else {
+        Ok(Expression::PropertyReference(reference))
+    }
+}
+
+/// Depth-first search of `root`'s element subtree for an element with id `id`.
+fn find_element_by_id(root: &ElementRc, id: &str) -> Option<ElementRc> {
+    if root.borrow().id == id {
+        return Some(root.clone());
+    }
+    for child in &root.borrow().children {
+        if let Some(found) = find_element_by_id(child, id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+/// Parses a single expression from source text, resolving every
+/// `PropertyReference`/callback it contains against `component_type` as it
+/// parses (see [`Parser::parse_primary`]/[`resolve_name`]).
+fn parse_expression(
+    source: &str,
+    component_type: &crate::ComponentDescription,
+) -> Result<Expression, EvalError> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser { tokens, pos: 0, component_type };
+    let expr = parser.parse_expr()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(EvalError::new("trailing input after expression"));
+    }
+    Ok(expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `resolve_name`/`Parser::parse_primary`'s identifier path need a live
+    // `crate::ComponentDescription` to resolve against, and this tree has no
+    // way to construct one outside of compiling a real `.60` file (it's
+    // assembled in `dynamic_component.rs`, which this source snapshot
+    // doesn't include): so dotted-name resolution isn't covered here. What
+    // follows exercises `is_balanced` and `tokenize`, which both only look at
+    // the raw source text.
+
+    #[test]
+    fn is_balanced_tracks_braces_parens_and_brackets() {
+        assert!(is_balanced("1 + 2"));
+        assert!(is_balanced("foo(1, [2, 3])"));
+        assert!(!is_balanced("foo(1, [2, 3)"));
+        assert!(!is_balanced("{ 1 + 2"));
+    }
+
+    #[test]
+    fn is_balanced_ignores_delimiters_inside_string_literals() {
+        assert!(is_balanced("\"(unbalanced\""));
+        assert!(is_balanced("\"escaped \\\" quote still { closes }\""));
+    }
+
+    #[test]
+    fn tokenize_folds_two_character_operators() {
+        let tokens = tokenize("a == b != c <= d >= e").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("a".into()),
+                Token::Op('='),
+                Token::Ident("b".into()),
+                Token::Op('!'),
+                Token::Ident("c".into()),
+                Token::Op('≤'),
+                Token::Ident("d".into()),
+                Token::Op('≥'),
+                Token::Ident("e".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_reads_numbers_dots_and_escaped_strings() {
+        let tokens = tokenize(r#"item.x 3.5 "a\"b""#).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("item".into()),
+                Token::Dot,
+                Token::Ident("x".into()),
+                Token::Number(3.5),
+                Token::String("a\"b".into()),
+            ]
+        );
+    }
+}